@@ -2,56 +2,13 @@ extern crate cfg_if;
 extern crate image;
 extern crate wasm_bindgen;
 
-mod skin;
+pub mod skin;
 mod utils;
 
-use image::DynamicImage;
 use js_sys::Uint8Array;
 use skin::*;
 use wasm_bindgen::prelude::*;
 
-enum RenderType {
-	Avatar,
-	Helm,
-	Cube,
-	Body,
-	Bust,
-	Cape,
-}
-
-struct RenderOptions {
-	armored: bool,
-	model: SkinModel,
-}
-
-impl RenderType {
-	fn render(self, img: &MinecraftSkin, size: u32, options: RenderOptions) -> DynamicImage {
-		match self {
-			RenderType::Avatar => img
-				.get_part(Layer::Bottom, BodyPart::Head, options.model)
-				.resize(size, size, image::imageops::FilterType::Nearest),
-			RenderType::Helm => img
-				.get_part(Layer::Both, BodyPart::Head, options.model)
-				.resize(size, size, image::imageops::FilterType::Nearest),
-			RenderType::Cube => img.render_cube(true, size),
-			RenderType::Body => img.render_body(options).resize(
-				size,
-				size * 2,
-				image::imageops::FilterType::Nearest,
-			),
-			RenderType::Bust => img.render_body(options).crop(0, 0, 16, 16).resize(
-				size,
-				size,
-				image::imageops::FilterType::Nearest,
-			),
-			RenderType::Cape => {
-				img.get_cape()
-					.resize(size, size, image::imageops::FilterType::Nearest)
-			}
-		}
-	}
-}
-
 fn what_to_render_type(what: String) -> Option<RenderType> {
 	match what.as_str() {
 		"avatar" => Some(RenderType::Avatar),
@@ -71,6 +28,7 @@ pub fn get_rendered_image(
 	what: String,
 	armored: bool,
 	slim: bool,
+	smooth: bool,
 ) -> Result<Uint8Array, JsValue> {
 	let render_type = what_to_render_type(what);
 	if render_type.is_none() {
@@ -87,10 +45,12 @@ pub fn get_rendered_image(
 				true => RenderOptions {
 					armored,
 					model: SkinModel::Slim,
+					smooth,
 				},
 				false => RenderOptions {
 					armored,
 					model: SkinModel::Regular,
+					smooth,
 				},
 			};
 			let rendered = render_type.unwrap().render(&skin, size, options);
@@ -2,8 +2,8 @@ extern crate image;
 
 use crate::skin::BodyPart::{ArmLeft, Body, Head, LegLeft};
 use crate::skin::Layer::Bottom;
-use crate::utils::{apply_minecraft_transparency, fast_overlay};
-use crate::RenderOptions;
+use crate::utils::{apply_minecraft_transparency, fast_overlay, resize_srgb};
+use image::imageops::FilterType;
 use image::{imageops, DynamicImage, GenericImageView, Rgba, RgbaImage};
 use imageproc::geometric_transformations::{warp_into, Interpolation, Projection};
 
@@ -16,8 +16,8 @@ pub(crate) enum MinecraftSkinVersion {
 	Invalid,
 }
 
-#[derive(Clone, Copy, PartialEq)]
-pub(crate) enum SkinModel {
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SkinModel {
 	Slim,
 	Regular,
 }
@@ -39,6 +39,27 @@ pub(crate) enum BodyPart {
 	LegRight,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenderType {
+	Avatar,
+	Helm,
+	Cube,
+	Body,
+	Bust,
+	Cape,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderOptions {
+	pub armored: bool,
+	pub model: SkinModel,
+	/// Downscale with a gamma-correct filter instead of nearest-neighbour. Has no effect on
+	/// renders that are only ever scaled up.
+	pub smooth: bool,
+}
+
+const SMOOTH_CUBE_SUPERSAMPLE: u32 = 4;
+
 const skew_a: f32 = 26.0 / 45.0; // 0.57777777
 const skew_b: f32 = skew_a * 2.0; // 1.15555555
 
@@ -177,7 +198,7 @@ impl MinecraftSkin {
 
 	pub(crate) fn render_cube(&self, overlay: bool, width: u32) -> DynamicImage {
 		let scale = (width as f32) / 20.0 as f32;
-		let height = (18.5 * scale).ceil() as u32;
+		let height = cube_height(width);
 		let _layer_type = match overlay {
 			true => Layer::Both,
 			false => Layer::Bottom,
@@ -243,3 +264,154 @@ impl MinecraftSkin {
 		DynamicImage::ImageRgba8(render)
 	}
 }
+
+fn cube_height(width: u32) -> u32 {
+	let scale = (width as f32) / 20.0;
+	(18.5 * scale).ceil() as u32
+}
+
+impl RenderType {
+	pub(crate) fn render(
+		self,
+		img: &MinecraftSkin,
+		size: u32,
+		options: RenderOptions,
+	) -> DynamicImage {
+		let smooth = options.smooth;
+		let (rendered, width, height) = match self {
+			RenderType::Avatar => (
+				img.get_part(Layer::Bottom, BodyPart::Head, options.model),
+				size,
+				size,
+			),
+			RenderType::Helm => (
+				img.get_part(Layer::Both, BodyPart::Head, options.model),
+				size,
+				size,
+			),
+			RenderType::Cube => {
+				if !smooth {
+					return img.render_cube(true, size);
+				}
+
+				// Render larger than requested so there is something for the gamma-correct
+				// downscale to average, then shrink to exactly what the plain render would be.
+				let rendered = img.render_cube(true, size * SMOOTH_CUBE_SUPERSAMPLE);
+				return DynamicImage::ImageRgba8(resize_srgb(
+					&rendered.into_rgba8(),
+					size,
+					cube_height(size),
+					FilterType::Triangle,
+				));
+			}
+			RenderType::Body => (img.render_body(options), size, size * 2),
+			RenderType::Bust => (img.render_body(options).crop(0, 0, 16, 16), size, size),
+			RenderType::Cape => (img.get_cape(), size, size),
+		};
+		resize(&rendered, width, height, smooth)
+	}
+}
+
+// Smooth (gamma-correct) filtering only applies when the image is being shrunk. Skin parts are
+// pixel art that is almost always scaled up, so in practice they stay on nearest-neighbour.
+fn resize(img: &DynamicImage, width: u32, height: u32, smooth: bool) -> DynamicImage {
+	if !smooth {
+		return img.resize(width, height, FilterType::Nearest);
+	}
+
+	// Keep the aspect ratio, like `DynamicImage::resize` does.
+	let (img_width, img_height) = img.dimensions();
+	let (img_width, img_height) = (u64::from(img_width), u64::from(img_height));
+	let (fit_width, fit_height) = (u64::from(width), u64::from(height));
+	let (fit_width, fit_height) = if fit_width * img_height <= fit_height * img_width {
+		(fit_width, (img_height * fit_width / img_width).max(1))
+	} else {
+		((img_width * fit_height / img_height).max(1), fit_height)
+	};
+
+	if fit_width >= img_width && fit_height >= img_height {
+		return img.resize(width, height, FilterType::Nearest);
+	}
+
+	// Both sides are bounded by the requested `u32` dimensions, so narrowing is lossless.
+	DynamicImage::ImageRgba8(resize_srgb(
+		&img.to_rgba8(),
+		fit_width as u32,
+		fit_height as u32,
+		FilterType::Triangle,
+	))
+}
+
+/// Renders `render_type` from a decoded Minecraft skin, scaled to `size` pixels wide.
+pub fn render_minecraft_skin(
+	img: &DynamicImage,
+	size: u32,
+	render_type: RenderType,
+	options: RenderOptions,
+) -> DynamicImage {
+	let skin = MinecraftSkin::new(img.clone());
+	render_type.render(&skin, size, options)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn render_avatar_is_requested_size() {
+		let img = DynamicImage::new_rgba8(64, 64);
+		let options = RenderOptions {
+			armored: false,
+			model: SkinModel::Regular,
+			smooth: false,
+		};
+		let rendered = render_minecraft_skin(&img, 128, RenderType::Avatar, options);
+		assert_eq!(rendered.dimensions(), (128, 128));
+	}
+
+	#[test]
+	fn render_keeps_nearest_dimensions() {
+		let img = DynamicImage::new_rgba8(64, 64);
+		let options = RenderOptions {
+			armored: false,
+			model: SkinModel::Regular,
+			smooth: false,
+		};
+		let rendered = render_minecraft_skin(&img, 100, RenderType::Cape, options);
+		assert_eq!(rendered.dimensions(), (62, 100));
+
+		let options = RenderOptions {
+			armored: false,
+			model: SkinModel::Slim,
+			smooth: false,
+		};
+		let rendered = render_minecraft_skin(&img, 100, RenderType::Body, options);
+		assert_eq!(rendered.dimensions(), (87, 200));
+	}
+
+	#[test]
+	fn render_smooth_cube_matches_nearest_dimensions() {
+		let mut img = RgbaImage::new(64, 64);
+		for (x, y, p) in img.enumerate_pixels_mut() {
+			*p = match (x + y) % 2 {
+				0 => Rgba([0xFF, 0xFF, 0xFF, 0xFF]),
+				_ => Rgba([0x00, 0x00, 0x00, 0xFF]),
+			};
+		}
+		let img = DynamicImage::ImageRgba8(img);
+
+		let render = |smooth| {
+			let options = RenderOptions {
+				armored: false,
+				model: SkinModel::Regular,
+				smooth,
+			};
+			render_minecraft_skin(&img, 100, RenderType::Cube, options).into_rgba8()
+		};
+		let nearest = render(false);
+		let smooth = render(true);
+
+		assert_eq!(smooth.dimensions(), nearest.dimensions());
+		assert_ne!(smooth.into_raw(), nearest.into_raw());
+	}
+}
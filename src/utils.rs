@@ -1,5 +1,6 @@
 use cfg_if::cfg_if;
-use image::{imageops, DynamicImage, GenericImage, GenericImageView};
+use image::imageops::FilterType;
+use image::{imageops, DynamicImage, GenericImage, GenericImageView, ImageBuffer, Rgba, RgbaImage};
 
 cfg_if! {
 	// When the `console_error_panic_hook` feature is enabled, we can call the
@@ -81,3 +82,115 @@ pub(crate) fn fast_overlay(bottom: &mut DynamicImage, top: &DynamicImage, x: u32
 		}
 	}
 }
+
+fn srgb_to_linear(c: u8) -> f32 {
+	let c = c as f32 / 255.0;
+	if c <= 0.04045 {
+		c / 12.92
+	} else {
+		((c + 0.055) / 1.055).powf(2.4)
+	}
+}
+
+fn linear_to_srgb(c: f32) -> u8 {
+	let c = c.clamp(0.0, 1.0);
+	let c = if c <= 0.0031308 {
+		c * 12.92
+	} else {
+		1.055 * c.powf(1.0 / 2.4) - 0.055
+	};
+	(c * 255.0).round() as u8
+}
+
+pub(crate) fn resize_srgb(
+	image: &RgbaImage,
+	width: u32,
+	height: u32,
+	filter: FilterType,
+) -> RgbaImage {
+	// Filtering straight sRGB values darkens the result, and averaging in the colour of transparent
+	// pixels (usually black) leaves a dark halo around the edges. Resize premultiplied linear values
+	// instead and convert back afterwards.
+	let (src_width, src_height) = image.dimensions();
+	let mut linear: ImageBuffer<Rgba<f32>, Vec<f32>> = ImageBuffer::new(src_width, src_height);
+	for (x, y, p) in image.enumerate_pixels() {
+		let alpha = p[3] as f32 / 255.0;
+		linear.put_pixel(
+			x,
+			y,
+			Rgba([
+				srgb_to_linear(p[0]) * alpha,
+				srgb_to_linear(p[1]) * alpha,
+				srgb_to_linear(p[2]) * alpha,
+				alpha,
+			]),
+		);
+	}
+
+	let resized = imageops::resize(&linear, width, height, filter);
+
+	let mut result = RgbaImage::new(width, height);
+	for (x, y, p) in resized.enumerate_pixels() {
+		let alpha = p[3].clamp(0.0, 1.0);
+		if alpha == 0.0 {
+			continue;
+		}
+		result.put_pixel(
+			x,
+			y,
+			Rgba([
+				linear_to_srgb(p[0] / alpha),
+				linear_to_srgb(p[1] / alpha),
+				linear_to_srgb(p[2] / alpha),
+				(alpha * 255.0).round() as u8,
+			]),
+		);
+	}
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn resize_srgb_keeps_edges_bright() {
+		let white = Rgba([0xFF, 0xFF, 0xFF, 0xFF]);
+		let transparent = Rgba([0x00, 0x00, 0x00, 0x00]);
+		let mut image = RgbaImage::new(2, 2);
+		image.put_pixel(0, 0, white);
+		image.put_pixel(1, 0, transparent);
+		image.put_pixel(0, 1, transparent);
+		image.put_pixel(1, 1, white);
+
+		let naive = imageops::resize(&image, 1, 1, FilterType::Triangle);
+		let correct = resize_srgb(&image, 1, 1, FilterType::Triangle);
+
+		let naive = naive.get_pixel(0, 0);
+		let correct = correct.get_pixel(0, 0);
+		assert!(correct[0] > naive[0]);
+		assert_eq!(correct[0], 0xFF);
+		assert_eq!(correct[3], naive[3]);
+	}
+
+	#[test]
+	fn resize_srgb_averages_in_linear_light() {
+		let white = Rgba([0xFF, 0xFF, 0xFF, 0xFF]);
+		let black = Rgba([0x00, 0x00, 0x00, 0xFF]);
+		let mut image = RgbaImage::new(2, 2);
+		image.put_pixel(0, 0, white);
+		image.put_pixel(1, 0, black);
+		image.put_pixel(0, 1, black);
+		image.put_pixel(1, 1, white);
+
+		let naive = imageops::resize(&image, 1, 1, FilterType::Triangle);
+		let correct = resize_srgb(&image, 1, 1, FilterType::Triangle);
+
+		// Half intensity in linear light is about 188 in sRGB, not the 128 a plain average gives.
+		let naive = naive.get_pixel(0, 0);
+		let correct = correct.get_pixel(0, 0);
+		assert!((naive[0] as i32 - 128).abs() <= 1);
+		assert!((correct[0] as i32 - 188).abs() <= 1);
+		assert_eq!(correct[3], 0xFF);
+	}
+}
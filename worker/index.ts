@@ -33,11 +33,11 @@ function decorateHeaders(interpreted: CraftheadRequest, headers: Headers, hitCac
 
 
 function getCacheKey(interpreted: CraftheadRequest): string {
-	return `https://crafthead.net/__public${CACHE_BUST}/${interpreted.requested}/${interpreted.armored}/${interpreted.model}/${interpreted.identity.toLocaleLowerCase('en-US')}/${interpreted.size}`;
+	return `https://crafthead.net/__public${CACHE_BUST}/${interpreted.requested}/${interpreted.armored}/${interpreted.model}${interpreted.smooth ? '/smooth' : ''}/${interpreted.identity.toLocaleLowerCase('en-US')}/${interpreted.size}`;
 }
 
 async function renderImage(skin: Response, request: CraftheadRequest): Promise<Response> {
-	const { size, requested, armored } = request;
+	const { size, requested, armored, smooth } = request;
 	const destinationHeaders = new Headers(skin.headers);
 	const slim = destinationHeaders.get('X-Crafthead-Skin-Model') === 'slim';
 	const skinArrayBuffer = await skin.arrayBuffer();
@@ -74,7 +74,7 @@ async function renderImage(skin: Response, request: CraftheadRequest): Promise<R
 		}
 	}
 
-	return new Response(get_rendered_image(skinBuf, size, which, armored, slim), {
+	return new Response(get_rendered_image(skinBuf, size, which, armored, slim, smooth), {
 		headers: destinationHeaders,
 	});
 }
@@ -29,6 +29,7 @@ export interface CraftheadRequest {
 	size: number;
 	armored: boolean;
 	model: string | null;
+	smooth: boolean;
 }
 
 function stringKindToRequestedKind(kind: string): RequestedKind | null {
@@ -112,6 +113,12 @@ export function interpretRequest(request: Request): CraftheadRequest | null {
 		return null;
 	}
 
+	// Smooth scaling only changes cubes and renders shrunk below their 16px source, so normalise it
+	// away everywhere else rather than caching identical images twice.
+	const shrinksBelowSource = size < 16 && [RequestedKind.Body, RequestedKind.Bust, RequestedKind.Cape].includes(requested);
+	const canDownscale = requested === RequestedKind.Cube || shrinksBelowSource;
+	const smooth = canDownscale && url.searchParams.get('smooth') === 'true';
+
 	let identityType: IdentityKind;
 	if (identity.length <= 16) {
 		identityType = IdentityKind.Username;
@@ -124,5 +131,5 @@ export function interpretRequest(request: Request): CraftheadRequest | null {
 		return null;
 	}
 
-	return { requested, requestedKindString, identityType, identity, size, armored, model };
+	return { requested, requestedKindString, identityType, identity, size, armored, model, smooth };
 }